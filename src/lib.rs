@@ -34,15 +34,17 @@
 //! Then, create an instance of [`AocDay`](./struct.AocDay.html). Look at its documentation for
 //! information.
 
+use std::any::Any;
 use std::fmt::Display;
 use std::fs::{File, OpenOptions, create_dir_all};
 use std::io::{Read, Write};
 use std::env;
 use std::error::Error;
 
-use time::{Date, Instant};
+use time::{Date, Duration, Instant};
 use colored::*;
-#[cfg(feature = "config-file")]
+// Used unconditionally (not gated behind `config-file`) since the local
+// answer cache below relies on it regardless of that feature.
 use toml::Value;
 
 #[derive(Debug, Copy, Clone)]
@@ -50,6 +52,7 @@ pub enum AocError {
     MissingSessionId,
     SpecifiedDateInFuture,
     NoPuzzleOnDate,
+    NoExampleFound,
 }
 
 impl Display for AocError {
@@ -58,6 +61,7 @@ impl Display for AocError {
             AocError::MissingSessionId => "No session ID specified",
             AocError::SpecifiedDateInFuture => "The specified puzzle date is in the future",
             AocError::NoPuzzleOnDate => "There was no puzzle on the specified date",
+            AocError::NoExampleFound => "Couldn't find an example input block in the puzzle text",
         };
         write!(f, "Error: {}", msg)
     }
@@ -65,8 +69,168 @@ impl Display for AocError {
 
 impl Error for AocError {}
 
-fn aoc_err(err: AocError) -> Result<(), Box<dyn Error>> {
-    Err(Box::new(err))
+/// The result of submitting an answer to the aoc website, returned by
+/// [`AocDay::submit()`](./struct.AocDay.html#method.submit).
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubmitOutcome {
+    /// The answer was correct.
+    Correct,
+    /// The answer was incorrect. `too_high` is `Some(true)`/`Some(false)` if
+    /// the website said the answer was too high/too low, or `None` if it
+    /// didn't say either way.
+    Incorrect { too_high: Option<bool> },
+    /// The site is rate-limiting submissions. `wait_seconds` holds the
+    /// number of seconds left to wait if the website reported one.
+    RateLimited { wait_seconds: Option<u64> },
+    /// The puzzle part being submitted for has already been solved, or the
+    /// answer was submitted for a part that hasn't been unlocked yet.
+    AlreadySolved,
+    /// This answer was already rejected in a previous run, according to the
+    /// local answer cache, so it wasn't submitted at all.
+    AlreadyRejected,
+    /// The response didn't contain any of the known marker substrings.
+    Unknown(String),
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::Incorrect { too_high: Some(true) } => write!(f, "That's not the right answer (too high)"),
+            SubmitOutcome::Incorrect { too_high: Some(false) } => write!(f, "That's not the right answer (too low)"),
+            SubmitOutcome::Incorrect { too_high: None } => write!(f, "That's not the right answer"),
+            SubmitOutcome::RateLimited { wait_seconds: Some(s) } => write!(f, "You gave an answer too recently, you have {}s left to wait", s),
+            SubmitOutcome::RateLimited { wait_seconds: None } => write!(f, "You gave an answer too recently"),
+            SubmitOutcome::AlreadySolved => write!(f, "You don't seem to be solving the right level"),
+            SubmitOutcome::AlreadyRejected => write!(f, "Refusing to submit, this answer was already rejected previously"),
+            SubmitOutcome::Unknown(response) => write!(f, "Couldn't tell whether the answer was correct, here's the response: {}", response),
+        }
+    }
+}
+
+/// Parse a wait hint like `"4m 30s"` or `"30s"` into a total number of
+/// seconds.
+fn parse_wait_duration(s: &str) -> Option<u64> {
+    let mut total = 0u64;
+    let mut num = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            num.push(c);
+        } else if c.is_whitespace() {
+            continue;
+        } else {
+            let n: u64 = num.parse().ok()?;
+            num.clear();
+            total += match c {
+                'h' => n * 3600,
+                'm' => n * 60,
+                's' => n,
+                _ => return None,
+            };
+        }
+    }
+    if !num.is_empty() {
+        return None;
+    }
+    Some(total)
+}
+
+/// Parse the HTML response aoc returns after submitting an answer into a
+/// [`SubmitOutcome`](./enum.SubmitOutcome.html).
+fn parse_submit_response(response: &str) -> SubmitOutcome {
+    if response.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if response.contains("You gave an answer too recently") {
+        // The hint reads e.g. "You have 4m 30s left to wait.", distinct from
+        // the earlier, unrelated "you have to wait" in the same response, so
+        // anchor on " left to wait" and scan backwards for the "you have "
+        // immediately before it rather than the first one in the response.
+        let lower = response.to_lowercase();
+        let wait_seconds = lower.find(" left to wait").and_then(|end| {
+            let start = lower[..end].rfind("you have ")? + "you have ".len();
+            parse_wait_duration(&response[start..end])
+        });
+        SubmitOutcome::RateLimited { wait_seconds }
+    } else if response.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::AlreadySolved
+    } else if response.contains("That's not the right answer") {
+        let too_high = if response.contains("too high") {
+            Some(true)
+        } else if response.contains("too low") {
+            Some(false)
+        } else {
+            None
+        };
+        SubmitOutcome::Incorrect { too_high }
+    } else {
+        SubmitOutcome::Unknown(response.to_string())
+    }
+}
+
+/// Pull out the contents of every `<article class="day-desc">` block in a
+/// puzzle page (there are two once part 2 is unlocked).
+fn extract_articles(html: &str) -> Vec<&str> {
+    let marker = "<article class=\"day-desc\">";
+    let mut articles = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(marker) {
+        let after_marker = &rest[start + marker.len()..];
+        if let Some(end) = after_marker.find("</article>") {
+            articles.push(&after_marker[..end]);
+            rest = &after_marker[end + "</article>".len()..];
+        } else {
+            break;
+        }
+    }
+    articles
+}
+
+/// Undo the handful of HTML entities aoc's puzzle pages actually use.
+fn decode_html_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Strip HTML tags out of a fragment, leaving plain text.
+fn strip_html_tags(html: &str) -> String {
+    let mut result = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {},
+        }
+    }
+    decode_html_entities(&result)
+}
+
+/// Extract the plain-text prose of a puzzle page, i.e. every
+/// `<article class="day-desc">` block with its tags stripped.
+fn extract_day_desc_text(html: &str) -> String {
+    extract_articles(html)
+        .iter()
+        .map(|article| strip_html_tags(article).trim().to_string())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Find the first `<pre><code>` block inside a puzzle page's
+/// `<article class="day-desc">` sections, if there is one.
+fn extract_first_code_block(html: &str) -> Option<String> {
+    for article in extract_articles(html) {
+        if let Some(start) = article.find("<pre><code>") {
+            let after = &article[start + "<pre><code>".len()..];
+            if let Some(end) = after.find("</code></pre>") {
+                return Some(decode_html_entities(&after[..end]).trim_end_matches('\n').to_string());
+            }
+        }
+    }
+    None
 }
 
 /// The `AocDay` struct stores information for an aoc day.
@@ -80,6 +244,7 @@ pub struct AocDay<T> {
     session_id: Option<String>,
     input_path: String,
     serializer: fn(String) -> T,
+    auto_submit: bool,
 }
 
 /// The `Puzzle` struct stores information for an aoc puzzle. Two puzzles
@@ -87,7 +252,36 @@ pub struct AocDay<T> {
 pub struct Puzzle<T, D> {
     part: u8,
     examples: Vec<String>,
+    /// The expected answer for each example, if one was provided. Kept as a
+    /// `String` so it can be compared against the solver's stringified
+    /// output without requiring `D: PartialEq`.
+    expected: Vec<Option<String>>,
     solver: fn(T) -> D,
+    /// Overrides the day's serializer when set, letting this puzzle parse
+    /// the raw input into whatever `T` its own solver expects, independent
+    /// of the day's own input type (see
+    /// [`Puzzle::with_serializer()`](./struct.Puzzle.html#method.with_serializer)).
+    /// Left unset, [`AocDay::test()`](./struct.AocDay.html#method.test),
+    /// [`AocDay::run()`](./struct.AocDay.html#method.run) and
+    /// [`AocDay::run_bench()`](./struct.AocDay.html#method.run_bench) fall
+    /// back to the day's serializer instead, which only works if it happens
+    /// to produce this same `T`.
+    serializer: Option<fn(String) -> T>,
+}
+
+/// Produce a puzzle's input, preferring its own serializer when it has one
+/// over the day's. A puzzle's serializer can parse into any `U` its solver
+/// wants, independent of the day's own `T` — when no override is given, the
+/// day's serializer is used instead, which only actually type-checks if `U`
+/// and `T` are the same type; a mismatch panics rather than silently
+/// producing the wrong input.
+fn resolve_puzzle_input<T: 'static, U: 'static>(day_serializer: fn(String) -> T, puzzle_serializer: Option<fn(String) -> U>, raw: String) -> U {
+    match puzzle_serializer {
+        Some(serializer) => serializer(raw),
+        None => *(Box::new(day_serializer(raw)) as Box<dyn Any>)
+            .downcast::<U>()
+            .unwrap_or_else(|_| panic!("this puzzle's solver expects a different input type than the day's serializer produces; call Puzzle::with_serializer to provide one")),
+    }
 }
 
 impl AocDay<String> {
@@ -108,6 +302,7 @@ impl AocDay<String> {
             session_id: env::var("AOC_SESSION_ID").ok(),
             input_path: format!("inputs/{}/day{}.txt", year, day),
             serializer: |x| x,
+            auto_submit: false,
         }
     }
 }
@@ -130,6 +325,7 @@ impl<T> AocDay<T> {
             session_id: env::var("AOC_SESSION_ID").ok(),
             input_path: format!("inputs/{}/day{}.txt", year, day),
             serializer,
+            auto_submit: false,
         }
     }
 
@@ -191,11 +387,158 @@ impl<T> AocDay<T> {
         self
     }
 
+    /// Have [`run()`](./struct.AocDay.html#method.run) automatically submit
+    /// the computed answer after printing it.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::AocDay;
+    ///
+    /// let mut day_8 = AocDay::new(2015, 8);
+    /// day_8.auto_submit(true);
+    /// ~~~~
+    pub fn auto_submit(&mut self, auto_submit: bool) {
+        self.auto_submit = auto_submit;
+    }
+
+    /// Chainable version of [`AocDay::auto_submit()`](./struct.AocDay.html#method.auto_submit)
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::AocDay;
+    ///
+    /// let day_8 = AocDay::new(2015, 8)
+    ///     .with_auto_submit(true);
+    /// ~~~~
+    pub fn with_auto_submit(mut self, auto_submit: bool) -> Self {
+        self.auto_submit(auto_submit);
+        self
+    }
+
+    /// Submit an answer for the given puzzle to the aoc website, and report
+    /// whether it was correct.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::{AocDay, Puzzle, SubmitOutcome};
+    ///
+    /// let day_5 = AocDay::new(2019, 5);
+    /// let part_1 = Puzzle::new(1, |x: String| x.chars().filter(|&y| y == 'z').count());
+    /// match day_5.submit(&part_1, "42") {
+    ///     Ok(SubmitOutcome::Correct) => println!("Correct!"),
+    ///     Ok(outcome) => println!("{}", outcome),
+    ///     Err(e) => println!("{}", e),
+    /// }
+    /// ~~~~
+    pub fn submit<U>(&self, puzzle: &Puzzle<U, impl Display>, answer: &str) -> Result<SubmitOutcome, Box<dyn Error>> {
+        if self.session_id.is_none() {
+            return Err(Box::new(AocError::MissingSessionId));
+        }
+
+        if self.cached_correct_answer(puzzle.part).as_deref() == Some(answer) {
+            return Ok(SubmitOutcome::Correct);
+        }
+        if self.cached_rejected_answers(puzzle.part).iter().any(|rejected| rejected == answer) {
+            return Ok(SubmitOutcome::AlreadyRejected);
+        }
+
+        let response = ureq::post(&format!("https://adventofcode.com/{}/day/{}/answer", self.year, self.day))
+            .set("Cookie", &(String::from("session=") + self.session_id.as_ref().unwrap()))
+            .set("Content-Type", "application/x-www-form-urlencoded")
+            .send_string(&format!("level={}&answer={}", puzzle.part, answer))
+            .into_string()?;
+
+        let outcome = parse_submit_response(&response);
+        match &outcome {
+            SubmitOutcome::Correct => self.cache_correct_answer(puzzle.part, answer)?,
+            SubmitOutcome::Incorrect { .. } => self.cache_rejected_answer(puzzle.part, answer)?,
+            _ => {},
+        }
+
+        Ok(outcome)
+    }
+
+    /// Path of the local cache file that [`submit()`](./struct.AocDay.html#method.submit)
+    /// and [`run()`](./struct.AocDay.html#method.run) use to remember which
+    /// answers were already confirmed correct or rejected, so they don't
+    /// burn the site's rate limit re-submitting them.
+    fn cache_path(&self) -> String {
+        format!("{}.answers.toml", self.input_path)
+    }
+
+    fn load_answer_cache(&self) -> Value {
+        std::fs::read_to_string(self.cache_path())
+            .ok()
+            .and_then(|contents| contents.parse().ok())
+            .unwrap_or_else(|| Value::Table(Default::default()))
+    }
+
+    fn save_answer_cache(&self, cache: &Value) -> Result<(), Box<dyn Error>> {
+        let path = self.cache_path();
+        create_dir_all(path.split('/').take(2).collect::<Vec<_>>().join("/"))?;
+        let mut file = File::create(path)?;
+        file.write_all(cache.to_string().as_bytes())?;
+        Ok(())
+    }
+
+    /// The confirmed-correct answer for `part`, if the cache has one.
+    fn cached_correct_answer(&self, part: u8) -> Option<String> {
+        self.load_answer_cache()
+            .get(part.to_string().as_str())
+            .and_then(|entry| entry.get("correct"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    }
+
+    /// The set of answers already known to be wrong for `part`.
+    fn cached_rejected_answers(&self, part: u8) -> Vec<String> {
+        self.load_answer_cache()
+            .get(part.to_string().as_str())
+            .and_then(|entry| entry.get("rejected"))
+            .and_then(Value::as_array)
+            .map(|rejected| rejected.iter().filter_map(Value::as_str).map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    fn cache_correct_answer(&self, part: u8, answer: &str) -> Result<(), Box<dyn Error>> {
+        let mut cache = self.load_answer_cache();
+        let part_entry = cache.as_table_mut().unwrap()
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Table(Default::default()));
+        part_entry.as_table_mut().unwrap().insert("correct".to_string(), Value::String(answer.to_string()));
+        self.save_answer_cache(&cache)
+    }
+
+    fn cache_rejected_answer(&self, part: u8, answer: &str) -> Result<(), Box<dyn Error>> {
+        let mut cache = self.load_answer_cache();
+        let part_entry = cache.as_table_mut().unwrap()
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Table(Default::default()));
+        let rejected = part_entry.as_table_mut().unwrap()
+            .entry("rejected".to_string())
+            .or_insert_with(|| Value::Array(Vec::new()));
+        if let Value::Array(rejected) = rejected {
+            if !rejected.iter().any(|v| v.as_str() == Some(answer)) {
+                rejected.push(Value::String(answer.to_string()));
+            }
+        }
+        self.save_answer_cache(&cache)
+    }
+
     /// Run a solver function on some example inputs. The function and the
     /// inputs should be provided using a
     /// [`Puzzle`](.//struct.Puzzle.html)
     /// instance.
     ///
+    /// If the puzzle's examples were given expected answers (see
+    /// [`Puzzle::with_examples_and_answers()`](./struct.Puzzle.html#method.with_examples_and_answers)),
+    /// each example's output is checked against its expected answer and a
+    /// `PASS`/`FAIL` is printed for it. Returns `true` if every example with
+    /// an expected answer passed, so callers can assert on it in CI.
+    ///
     /// # Example
     ///
     /// ~~~~
@@ -207,11 +550,29 @@ impl<T> AocDay<T> {
     ///         .with_examples(&["test", "cases"])
     /// );
     /// ~~~~
-    pub fn test(&self, puzzle: &Puzzle<T, impl Display>) {
+    pub fn test<U: 'static>(&self, puzzle: &Puzzle<U, impl Display>) -> bool
+    where
+        T: 'static,
+    {
         println!("Testing day {} of AOC {}", self.day, self.year);
+        let mut all_passed = true;
         for (i, example) in puzzle.examples.iter().enumerate() {
-            println!("Part {}, Example {}: {}", puzzle.part, i + 1, (puzzle.solver)((self.serializer)(example.to_string())));
+            let input = resolve_puzzle_input(self.serializer, puzzle.serializer, example.to_string());
+            let output = (puzzle.solver)(input).to_string();
+            match puzzle.expected.get(i).and_then(Option::as_ref) {
+                Some(expected) if expected == &output => {
+                    println!("Part {}, Example {}: {} {}", puzzle.part, i + 1, output, "PASS".green());
+                },
+                Some(expected) => {
+                    println!("Part {}, Example {}: {} {}", puzzle.part, i + 1, output, format!("FAIL (expected {}, got {})", expected, output).red());
+                    all_passed = false;
+                },
+                None => {
+                    println!("Part {}, Example {}: {}", puzzle.part, i + 1, output);
+                },
+            }
         }
+        all_passed
     }
 
     /// Run a solver function on the day's input. The function should be
@@ -240,10 +601,45 @@ impl<T> AocDay<T> {
     /// day_5.run(&part_1);
     /// day_5.run(&part_2);
     /// ~~~~
-    pub fn run(&mut self, puzzle: &Puzzle<T, impl Display>) -> Result<(), Box<dyn Error>> {
+    pub fn run<U: 'static>(&mut self, puzzle: &Puzzle<U, impl Display>) -> Result<(), Box<dyn Error>>
+    where
+        T: 'static,
+    {
+        if let Some(correct) = self.cached_correct_answer(puzzle.part) {
+            println!("[{} {}, {} {}, {} {}]: {} {}",
+                "AoC".yellow(), self.year,
+                "day".bright_cyan(), self.day,
+                "part".bright_cyan(), puzzle.part,
+                correct.bright_white(), "(cached)".bright_green());
+            return Ok(());
+        }
+
+        let contents = self.fetch_input(puzzle.part)?;
+
+        let input = resolve_puzzle_input(self.serializer, puzzle.serializer, contents.trim().to_string());
+        let start_time = Instant::now();
+        let output = (puzzle.solver)(input);
+        let elapsed = start_time.elapsed();
+        println!("{}", output.to_string().bright_white());
+        println!("{} {}", "Finished in".bright_green(), format_duration(elapsed));
+
+        if self.auto_submit {
+            match self.submit(puzzle, &output.to_string()) {
+                Ok(outcome) => println!("{}", outcome),
+                Err(e) => println!("{}", e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Make sure we have a session ID (falling back to the `aoc_helper.toml`
+    /// config file if the `config-file` feature is enabled) and that the
+    /// puzzle date is one the website could plausibly have.
+    fn ensure_session(&mut self) -> Result<(), Box<dyn Error>> {
         #[cfg(feature = "config-file")]
         {
-            if self.session_id == None {
+            if self.session_id.is_none() {
                 // Try to get session ID from config file
                 if let Ok(mut config_file) = File::open("aoc_helper.toml") {
                     let mut contents = String::new();
@@ -255,8 +651,8 @@ impl<T> AocDay<T> {
                 }
             }
         }
-        if self.session_id == None {
-            return aoc_err(AocError::MissingSessionId);
+        if self.session_id.is_none() {
+            return Err(Box::new(AocError::MissingSessionId));
         }
 
         let running_date = Date::try_from_ymd(self.year, 12, self.day).unwrap();
@@ -264,11 +660,21 @@ impl<T> AocDay<T> {
         let today = Date::today();
         let max_year = if today.month() < 12 { today.year() - 1 } else { today.year() };
         if running_date > Date::try_from_ymd(max_year, 12, 25).unwrap() {
-            return aoc_err(AocError::SpecifiedDateInFuture);
+            return Err(Box::new(AocError::SpecifiedDateInFuture));
         } else if self.day > 25 || running_date < Date::try_from_ymd(2015, 12, 1).unwrap() {
-            return aoc_err(AocError::NoPuzzleOnDate);
+            return Err(Box::new(AocError::NoPuzzleOnDate));
         }
 
+        Ok(())
+    }
+
+    /// Fetch the day's input, downloading and caching it if it isn't already
+    /// on disk, and print the `[AoC year, day x, part y]:` prefix used by
+    /// both [`run()`](./struct.AocDay.html#method.run) and
+    /// [`run_bench()`](./struct.AocDay.html#method.run_bench).
+    fn fetch_input(&mut self, part: u8) -> Result<String, Box<dyn Error>> {
+        self.ensure_session()?;
+
         let mut input_file = match OpenOptions::new()
             .read(true)
             .write(true)
@@ -288,9 +694,8 @@ impl<T> AocDay<T> {
                 }
             },
         };
-        
+
         let mut contents = String::new();
-        println!("{}", contents);
         input_file.read_to_string(&mut contents)?;
         if contents.len() == 0 {
             // Get the input from the website
@@ -300,59 +705,181 @@ impl<T> AocDay<T> {
             let mut input_file = File::open(&self.input_path)?;
             input_file.read_to_string(&mut contents)?;
         }
-        
+
         print!("[{} {}, {} {}, {} {}]: ",
             "AoC".yellow(), self.year,
             "day".bright_cyan(), self.day,
-            "part".bright_cyan(), puzzle.part);
+            "part".bright_cyan(), part);
         std::io::stdout().flush()?;
 
-        let input = (self.serializer)(contents.trim().to_string());
+        Ok(contents)
+    }
+
+    /// Path the puzzle's prose is cached at: `inputs/{year}/day{day}_puzzle.md`,
+    /// next to the default input file. A custom input path set with
+    /// [`input()`](./struct.AocDay.html#method.input) does not move it.
+    fn puzzle_text_path(&self) -> String {
+        format!("inputs/{}/day{}_puzzle.md", self.year, self.day)
+    }
+
+    /// Fetch the raw HTML of the puzzle's page, mirroring how
+    /// [`fetch_input()`](#method.fetch_input) fetches the input.
+    fn fetch_day_page(&mut self) -> Result<String, Box<dyn Error>> {
+        self.ensure_session()?;
+
+        Ok(ureq::get(&format!("https://adventofcode.com/{}/day/{}", self.year, self.day))
+            .set("Cookie", &(String::from("session=") + self.session_id.as_ref().unwrap()))
+            .call()
+            .into_string()?)
+    }
+
+    /// Fetch the puzzle's prose as plain text, caching it at
+    /// `inputs/{year}/day{day}_puzzle.md` (next to the default input file,
+    /// whatever input path is set) so it's only downloaded once.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::AocDay;
+    ///
+    /// let mut day_5 = AocDay::new(2019, 5);
+    /// println!("{}", day_5.puzzle_text().unwrap());
+    /// ~~~~
+    pub fn puzzle_text(&mut self) -> Result<String, Box<dyn Error>> {
+        let path = self.puzzle_text_path();
+        if let Ok(contents) = std::fs::read_to_string(&path) {
+            if !contents.is_empty() {
+                return Ok(contents);
+            }
+        }
+
+        let html = self.fetch_day_page()?;
+        let text = extract_day_desc_text(&html);
+
+        create_dir_all(path.split('/').take(2).collect::<Vec<_>>().join("/"))?;
+        File::create(&path)?.write_all(text.as_bytes())?;
+
+        Ok(text)
+    }
+
+    /// Pull the first `<pre><code>` block out of the puzzle's page as a
+    /// candidate example input, so it doesn't have to be hand-copied from
+    /// the problem statement. See
+    /// [`Puzzle::with_extracted_example()`](./struct.Puzzle.html#method.with_extracted_example).
+    pub fn extract_example(&mut self) -> Result<String, Box<dyn Error>> {
+        let html = self.fetch_day_page()?;
+        extract_first_code_block(&html).ok_or_else(|| Box::new(AocError::NoExampleFound) as Box<dyn Error>)
+    }
+}
+
+impl<T> AocDay<T> {
+    /// Run a solver function on the day's input repeatedly and report
+    /// min/mean/max timings, instead of timing a single noisy run like
+    /// [`run()`](./struct.AocDay.html#method.run) does.
+    ///
+    /// After an initial correctness run (whose output is printed, same as
+    /// `run()`), the solver is invoked again on a cloned input until a
+    /// wall-clock budget of about 1 second has elapsed, or 10 samples have
+    /// been collected, whichever takes longer. Each sample is passed
+    /// through a `black_box`-style identity barrier so the optimizer can't
+    /// elide it.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::{AocDay, Puzzle};
+    ///
+    /// let mut day_5 = AocDay::new(2019, 5);
+    /// let part_1 = Puzzle::new(1, |x: String| x.chars().filter(|&y| y == 'z').count());
+    /// day_5.run_bench(&part_1).unwrap();
+    /// ~~~~
+    pub fn run_bench<U: 'static + Clone>(&mut self, puzzle: &Puzzle<U, impl Display>) -> Result<(), Box<dyn Error>>
+    where
+        T: 'static,
+    {
+        const MIN_SAMPLES: usize = 10;
+        let bench_budget = Duration::seconds(1);
+
+        let contents = self.fetch_input(puzzle.part)?;
+        let input = resolve_puzzle_input(self.serializer, puzzle.serializer, contents.trim().to_string());
+
         let start_time = Instant::now();
-        let output = (puzzle.solver)(input);
+        let output = (puzzle.solver)(black_box(input.clone()));
         let elapsed = start_time.elapsed();
         println!("{}", output.to_string().bright_white());
+        println!("{} {}", "Finished in".bright_green(), format_duration(elapsed));
+
+        let mut samples = Vec::new();
+        let bench_start = Instant::now();
+        while samples.len() < MIN_SAMPLES || bench_start.elapsed() < bench_budget {
+            let sample_input = black_box(input.clone());
+            let start = Instant::now();
+            let sample_output = (puzzle.solver)(sample_input);
+            samples.push(start.elapsed());
+            black_box(sample_output);
+        }
+
+        let min = samples.iter().min().copied().unwrap();
+        let max = samples.iter().max().copied().unwrap();
+        let total_nanos: i128 = samples.iter().map(|d| d.whole_nanoseconds()).sum();
+        let mean = Duration::nanoseconds((total_nanos / samples.len() as i128) as i64);
+
+        println!("{} {} samples", "Benchmarked".bright_green(), samples.len());
+        println!("  {} {}", "min:".bright_cyan(), format_duration(min));
+        println!("  {} {}", "mean:".bright_cyan(), format_duration(mean));
+        println!("  {} {}", "max:".bright_cyan(), format_duration(max));
 
-        let time_taken = {
-            let mut msg_str = String::new();
-            let (d, h, m, s, ms, us, ns) = (
-                elapsed.whole_days(),
-                elapsed.whole_hours() % 24,
-                elapsed.whole_minutes() % 60,
-                elapsed.whole_seconds() % 60,
-                elapsed.whole_milliseconds() % 1000,
-                elapsed.whole_microseconds() % 1000,
-                elapsed.whole_nanoseconds() % 1000,
-            );
-            if d > 0 {
-                msg_str.push_str(&format!("{}d ", d));
-            }
-            if h > 0 {
-                msg_str.push_str(&format!("{}h ", h));
-            }
-            if m > 0 {
-                msg_str.push_str(&format!("{}m ", m));
-            }
-            if s > 0 {
-                msg_str.push_str(&format!("{}s ", s));
-            }
-            if ms > 0 {
-                msg_str.push_str(&format!("{}ms ", ms));
-            }
-            if us > 0 {
-                msg_str.push_str(&format!("{}us ", us));
-            }
-            if ns > 0 {
-                msg_str.push_str(&format!("{}ns ", ns));
-            }
-            msg_str
-        };
-        println!("{} {}", "Finished in".bright_green(), time_taken);
-        
         Ok(())
     }
 }
 
+/// Format a `Duration` the same human-readable way `run()` reports timings,
+/// e.g. `1m 2s 3ms`.
+fn format_duration(elapsed: Duration) -> String {
+    let mut msg_str = String::new();
+    let (d, h, m, s, ms, us, ns) = (
+        elapsed.whole_days(),
+        elapsed.whole_hours() % 24,
+        elapsed.whole_minutes() % 60,
+        elapsed.whole_seconds() % 60,
+        elapsed.whole_milliseconds() % 1000,
+        elapsed.whole_microseconds() % 1000,
+        elapsed.whole_nanoseconds() % 1000,
+    );
+    if d > 0 {
+        msg_str.push_str(&format!("{}d ", d));
+    }
+    if h > 0 {
+        msg_str.push_str(&format!("{}h ", h));
+    }
+    if m > 0 {
+        msg_str.push_str(&format!("{}m ", m));
+    }
+    if s > 0 {
+        msg_str.push_str(&format!("{}s ", s));
+    }
+    if ms > 0 {
+        msg_str.push_str(&format!("{}ms ", ms));
+    }
+    if us > 0 {
+        msg_str.push_str(&format!("{}us ", us));
+    }
+    if ns > 0 {
+        msg_str.push_str(&format!("{}ns ", ns));
+    }
+    msg_str
+}
+
+/// An identity function that prevents the optimizer from eliding the call
+/// it wraps, used to keep benchmark samples honest.
+fn black_box<V>(dummy: V) -> V {
+    unsafe {
+        let ret = std::ptr::read_volatile(&dummy);
+        std::mem::forget(dummy);
+        ret
+    }
+}
+
 impl<T, D: Display> Puzzle<T, D> {
     /// Create a new `Puzzle` instance for the provided part number and solver
     /// function.
@@ -368,7 +895,9 @@ impl<T, D: Display> Puzzle<T, D> {
         Puzzle {
             part,
             examples: Vec::new(),
+            expected: Vec::new(),
             solver,
+            serializer: None,
         }
     }
 
@@ -387,6 +916,7 @@ impl<T, D: Display> Puzzle<T, D> {
     /// ~~~~
     pub fn examples<S: ToString>(&mut self, examples: &[S]) {
         self.examples = examples.iter().map(|example| example.to_string()).collect();
+        self.expected = self.examples.iter().map(|_| None).collect();
     }
 
     /// Chainable version of [`Puzzle::examples()`](./struct.Puzzle.html#method.examples)
@@ -403,4 +933,147 @@ impl<T, D: Display> Puzzle<T, D> {
         self.examples(examples);
         self
     }
+
+    /// Provide some example inputs along with the answer each one is
+    /// expected to produce, so that
+    /// [`AocDay::test()`](./struct.AocDay.html#method.test)
+    /// can verify the solver's output instead of just printing it.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::Puzzle;
+    ///
+    /// let mut part_2 = Puzzle::new(2, |x: String| x.lines().count());
+    /// part_2.examples_and_answers(&[("a\nb", 2), ("a\nb\nc", 3)]);
+    /// ~~~~
+    pub fn examples_and_answers<S: ToString, E: ToString>(&mut self, examples: &[(S, E)]) {
+        self.examples = examples.iter().map(|(example, _)| example.to_string()).collect();
+        self.expected = examples.iter().map(|(_, expected)| Some(expected.to_string())).collect();
+    }
+
+    /// Chainable version of [`Puzzle::examples_and_answers()`](./struct.Puzzle.html#method.examples_and_answers)
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::Puzzle;
+    ///
+    /// let part_2 = Puzzle::new(2, |x: String| x.lines().count())
+    ///     .with_examples_and_answers(&[("a\nb", 2), ("a\nb\nc", 3)]);
+    /// ~~~~
+    pub fn with_examples_and_answers<S: ToString, E: ToString>(mut self, examples: &[(S, E)]) -> Self {
+        self.examples_and_answers(examples);
+        self
+    }
+
+    /// Fetch the puzzle's page and add its first `<pre><code>` block as an
+    /// example input, instead of hand-copying the sample from the problem
+    /// statement. Unlike the other `with_*` methods this hits the network
+    /// (through [`AocDay::extract_example()`](./struct.AocDay.html#method.extract_example)),
+    /// so it returns a `Result`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::{AocDay, Puzzle};
+    ///
+    /// let mut day_5 = AocDay::new(2019, 5);
+    /// let part_1 = Puzzle::new(1, |x: String| x.chars().filter(|&y| y == 'z').count())
+    ///     .with_extracted_example(&mut day_5)
+    ///     .unwrap();
+    /// ~~~~
+    pub fn with_extracted_example<V>(mut self, day: &mut AocDay<V>) -> Result<Self, Box<dyn Error>> {
+        let example = day.extract_example()?;
+        self.examples.push(example);
+        self.expected.push(None);
+        Ok(self)
+    }
+
+    /// Override the day's serializer function for this puzzle specifically.
+    /// This is useful when a part wants to parse the raw input into a
+    /// different shape than the day's default serializer produces — e.g.
+    /// part 1 wanting `Vec<i64>` and part 2 wanting a grid — since this
+    /// puzzle's own `T` no longer has to match the day's input type.
+    /// [`AocDay::test()`](./struct.AocDay.html#method.test),
+    /// [`AocDay::run()`](./struct.AocDay.html#method.run) and
+    /// [`AocDay::run_bench()`](./struct.AocDay.html#method.run_bench) use
+    /// this serializer instead of the day's when it's set; left unset, they
+    /// fall back to the day's serializer, which only works if it happens to
+    /// produce this same `T` (it panics otherwise).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::Puzzle;
+    ///
+    /// let mut part_1 = Puzzle::new(1, |x: Vec<String>| x.len());
+    /// part_1.serializer(|input| input.lines().map(str::to_string).collect());
+    /// ~~~~
+    pub fn serializer(&mut self, serializer: fn(String) -> T) {
+        self.serializer = Some(serializer);
+    }
+
+    /// Chainable version of [`Puzzle::serializer()`](./struct.Puzzle.html#method.serializer)
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::Puzzle;
+    ///
+    /// let part_1 = Puzzle::new(1, |x: Vec<String>| x.len())
+    ///     .with_serializer(|input| input.lines().map(str::to_string).collect());
+    /// ~~~~
+    pub fn with_serializer(mut self, serializer: fn(String) -> T) -> Self {
+        self.serializer(serializer);
+        self
+    }
+}
+
+/// Common serializer functions for use with
+/// [`AocDay::new_with_serializer()`](./struct.AocDay.html#method.new_with_serializer)
+/// and [`Puzzle::with_serializer()`](./struct.Puzzle.html#method.with_serializer),
+/// so you don't have to keep rewriting the same `input.split("\n\n")`
+/// boilerplate.
+pub mod serializers {
+    use std::str::FromStr;
+
+    /// Split the input into a `Vec` of lines.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::serializers::lines;
+    ///
+    /// assert_eq!(lines("foo\nbar".to_string()), vec!["foo", "bar"]);
+    /// ~~~~
+    pub fn lines(input: String) -> Vec<String> {
+        input.lines().map(str::to_string).collect()
+    }
+
+    /// Split the input on whitespace and parse each piece into `N`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::serializers::numbers;
+    ///
+    /// assert_eq!(numbers::<i64>("1 2\n3".to_string()), vec![1, 2, 3]);
+    /// ~~~~
+    pub fn numbers<N: FromStr>(input: String) -> Vec<N> where N::Err: std::fmt::Debug {
+        input.split_whitespace().map(|n| n.parse().unwrap()).collect()
+    }
+
+    /// Split the input on blank lines into a `Vec` of blocks.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use aoc_helper::serializers::blocks;
+    ///
+    /// assert_eq!(blocks("foo\nbar\n\nbaz".to_string()), vec!["foo\nbar", "baz"]);
+    /// ~~~~
+    pub fn blocks(input: String) -> Vec<String> {
+        input.split("\n\n").map(str::to_string).collect()
+    }
 }